@@ -0,0 +1,331 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Vector2, Vector3i};
+
+use super::glam_helpers::{GlamConv, GlamType};
+use super::{I64Vec2, IVec2};
+
+/// Vector used for 2D math using integer coordinates.
+///
+/// 2-element structure that can be used to represent 2D grid coordinates or sets of integers.
+///
+/// It uses integer coordinates and is therefore preferable to [`Vector2`] when exact precision is
+/// required. Note that the values are limited to 32 bits, and unlike [`Vector2`] this cannot be
+/// configured with an engine build option. Use [`Vector2i64`] or [`PackedInt64Array`] if 64-bit
+/// values are needed.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector2i {
+    /// The vector's X component.
+    pub x: i32,
+
+    /// The vector's Y component.
+    pub y: i32,
+}
+
+impl_vector_operators!(Vector2i, i32, (x, y));
+impl_vector_index!(Vector2i, i32, (x, y), Vector2iAxis, (X, Y));
+impl_common_vector_fns!(Vector2i, i32);
+impl_vector_bool_cmp!(Vector2i, Vector2b, (x, y));
+impl_vector_overflow_ops!(Vector2i, (x, y));
+impl_vector_int_math!(Vector2i, i32, i128, i64, (x, y));
+
+impl Vector2i {
+    /// Returns a `Vector2i` with the given components.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Constructs a new `Vector2i` with all components set to `v`.
+    pub const fn splat(v: i32) -> Self {
+        Self::new(v, v)
+    }
+
+    /// Constructs a new `Vector2i` from a [`Vector2`]. The floating point coordinates will be
+    /// truncated.
+    pub const fn from_vector2(v: Vector2) -> Self {
+        Self {
+            x: v.x as i32,
+            y: v.y as i32,
+        }
+    }
+
+    /// Extends `self` to a [`Vector3i`] by appending a `z` component.
+    pub const fn extend(self, z: i32) -> Vector3i {
+        Vector3i::from_vector2i(self, z)
+    }
+
+    /// Alias for [`Self::extend`], named after the component it appends.
+    pub const fn with_z(self, z: i32) -> Vector3i {
+        self.extend(z)
+    }
+
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::splat(1);
+
+    /// Converts the corresponding `glam` type to `Self`.
+    fn from_glam(v: IVec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+
+    /// Converts `self` to the corresponding `glam` type.
+    fn to_glam(self) -> IVec2 {
+        IVec2::new(self.x, self.y)
+    }
+}
+
+/// Formats the vector like Godot: `(x, y)`.
+impl fmt::Display for Vector2i {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// SAFETY:
+// This type is represented as `Self` in Godot, so `*mut Self` is sound.
+unsafe impl GodotFfi for Vector2i {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+/// Enumerates the axes in a [`Vector2i`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[repr(i32)]
+pub enum Vector2iAxis {
+    /// The X axis.
+    X,
+
+    /// The Y axis.
+    Y,
+}
+
+// SAFETY:
+// This type is represented as `Self` in Godot, so `*mut Self` is sound.
+unsafe impl GodotFfi for Vector2iAxis {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+impl GlamType for IVec2 {
+    type Mapped = Vector2i;
+
+    fn to_front(&self) -> Self::Mapped {
+        Vector2i::new(self.x, self.y)
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        IVec2::new(mapped.x, mapped.y)
+    }
+}
+
+impl GlamConv for Vector2i {
+    type Glam = IVec2;
+}
+
+/// Vector used for 2D math using 64-bit integer coordinates.
+///
+/// 2-element structure that can be used to represent 2D grid coordinates or sets of integers,
+/// for cases where [`Vector2i`]'s 32-bit components would overflow (large voxel grids, hashed
+/// coordinates, etc.).
+///
+/// Unlike [`Vector2i`], this is a Rust-only convenience type: Godot has no corresponding
+/// 64-bit vector, so `Vector2i64` does not implement [`GodotFfi`] and cannot be marshalled
+/// across the engine boundary.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector2i64 {
+    /// The vector's X component.
+    pub x: i64,
+
+    /// The vector's Y component.
+    pub y: i64,
+}
+
+impl_vector_operators!(Vector2i64, i64, (x, y));
+impl_vector_index!(Vector2i64, i64, (x, y), Vector2iAxis, (X, Y));
+impl_common_vector_fns!(Vector2i64, i64);
+impl_vector_overflow_ops!(Vector2i64, (x, y));
+impl_vector_int_math!(Vector2i64, i64, i128, i128, (x, y));
+
+impl Vector2i64 {
+    /// Returns a `Vector2i64` with the given components.
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Constructs a new `Vector2i64` with all components set to `v`.
+    pub const fn splat(v: i64) -> Self {
+        Self::new(v, v)
+    }
+
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::splat(1);
+
+    /// Narrows `self` to a [`Vector2i`], truncating any component that doesn't fit in `i32`.
+    pub fn as_vector2i(self) -> Vector2i {
+        Vector2i::new(self.x as i32, self.y as i32)
+    }
+
+    /// Narrows `self` to a [`Vector2i`], or returns `None` if any component overflows `i32`.
+    pub fn try_to_vector2i(self) -> Option<Vector2i> {
+        Some(Vector2i::new(
+            i32::try_from(self.x).ok()?,
+            i32::try_from(self.y).ok()?,
+        ))
+    }
+
+    /// Converts the corresponding `glam` type to `Self`.
+    fn from_glam(v: I64Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+
+    /// Converts `self` to the corresponding `glam` type.
+    fn to_glam(self) -> I64Vec2 {
+        I64Vec2::new(self.x, self.y)
+    }
+}
+
+/// Widens a [`Vector2i`] into a [`Vector2i64`]. This conversion is always lossless.
+impl From<Vector2i> for Vector2i64 {
+    fn from(v: Vector2i) -> Self {
+        Self::new(v.x as i64, v.y as i64)
+    }
+}
+
+/// Formats the vector like Godot: `(x, y)`.
+impl fmt::Display for Vector2i64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl GlamType for I64Vec2 {
+    type Mapped = Vector2i64;
+
+    fn to_front(&self) -> Self::Mapped {
+        Vector2i64::new(self.x, self.y)
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        I64Vec2::new(mapped.x, mapped.y)
+    }
+}
+
+impl GlamConv for Vector2i64 {
+    type Glam = I64Vec2;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coord_min_max() {
+        let a = Vector2i::new(1, 3);
+        let b = Vector2i::new(0, 5);
+        assert_eq!(a.coord_min(b), Vector2i::new(0, 3));
+        assert_eq!(a.coord_max(b), Vector2i::new(1, 5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let vector = Vector2i::default();
+        let expected_json = "{\"x\":0,\"y\":0}";
+
+        crate::builtin::test_utils::roundtrip(&vector, expected_json);
+    }
+
+    #[test]
+    fn cmp_and_select() {
+        let a = Vector2i::new(1, 3);
+        let b = Vector2i::new(0, 3);
+
+        assert_eq!(a.cmpeq(b), Vector2b::new(false, true));
+        assert_eq!(a.cmpne(b), Vector2b::new(true, false));
+        assert_eq!(a.cmplt(b), Vector2b::new(false, false));
+        assert_eq!(a.cmple(b), Vector2b::new(false, true));
+        assert_eq!(a.cmpgt(b), Vector2b::new(true, false));
+        assert_eq!(a.cmpge(b), Vector2b::new(true, true));
+
+        let mask = a.cmpgt(b);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(Vector2i::select(mask, a, b), Vector2i::new(1, 3));
+    }
+
+    #[test]
+    fn overflow_aware_arithmetic() {
+        let a = Vector2i::new(i32::MAX, i32::MIN);
+        let b = Vector2i::new(1, -1);
+
+        assert_eq!(a.wrapping_add(b), Vector2i::new(i32::MIN, i32::MAX));
+        assert_eq!(a.saturating_add(b), Vector2i::new(i32::MAX, i32::MIN));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(Vector2i::ONE.checked_add(Vector2i::ONE), Some(Vector2i::splat(2)));
+    }
+
+    #[test]
+    fn dimension_conversion() {
+        let v = Vector2i::new(1, 2);
+        assert_eq!(v.extend(3), Vector3i::new(1, 2, 3));
+        assert_eq!(v.with_z(3), v.extend(3));
+    }
+
+    #[test]
+    fn integer_math_fns() {
+        let a = Vector2i::new(-3, 4);
+
+        assert_eq!(a.abs(), Vector2i::new(3, 4));
+        assert_eq!(a.signum(), Vector2i::new(-1, 1));
+        assert_eq!(
+            a.clamp(Vector2i::splat(-1), Vector2i::splat(1)),
+            Vector2i::new(-1, 1)
+        );
+        assert_eq!(a.element_sum(), 1);
+        assert_eq!(a.element_product(), -12);
+
+        let c = Vector2i::new(7, -7);
+        assert_eq!(c.snapped(Vector2i::splat(5)), Vector2i::new(5, -5));
+
+        // Exact ties round half away from zero.
+        let tie = Vector2i::new(5, -5);
+        assert_eq!(tie.snapped(Vector2i::splat(2)), Vector2i::new(6, -6));
+    }
+
+    #[test]
+    fn widen_and_narrow_64_bit() {
+        let small = Vector2i::new(1, -2);
+        let wide = Vector2i64::from(small);
+        assert_eq!(wide, Vector2i64::new(1, -2));
+        assert_eq!(wide.try_to_vector2i(), Some(small));
+
+        let overflowing = Vector2i64::new(i64::from(i32::MAX) + 1, 0);
+        assert_eq!(overflowing.try_to_vector2i(), None);
+        assert_eq!(overflowing.as_vector2i(), Vector2i::new(i32::MIN, 0));
+    }
+
+    #[test]
+    fn snapped_64_bit_preserves_precision() {
+        let huge = Vector2i64::splat(i64::MAX - 1);
+        assert_eq!(huge.snapped(Vector2i64::splat(1)), huge);
+
+        let tie = Vector2i64::new(5, -5);
+        assert_eq!(tie.snapped(Vector2i64::splat(2)), Vector2i64::new(6, -6));
+    }
+}
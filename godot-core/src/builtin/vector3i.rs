@@ -0,0 +1,350 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Vector2i, Vector3, Vector4i};
+
+use super::glam_helpers::{GlamConv, GlamType};
+use super::{I64Vec3, IVec3};
+
+/// Vector used for 3D math using integer coordinates.
+///
+/// 3-element structure that can be used to represent 3D grid coordinates or sets of integers.
+///
+/// It uses integer coordinates and is therefore preferable to [`Vector3`] when exact precision is
+/// required. Note that the values are limited to 32 bits, and unlike [`Vector3`] this cannot be
+/// configured with an engine build option. Use [`Vector3i64`] or [`PackedInt64Array`] if 64-bit
+/// values are needed.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector3i {
+    /// The vector's X component.
+    pub x: i32,
+
+    /// The vector's Y component.
+    pub y: i32,
+
+    /// The vector's Z component.
+    pub z: i32,
+}
+
+impl_vector_operators!(Vector3i, i32, (x, y, z));
+impl_vector_index!(Vector3i, i32, (x, y, z), Vector3iAxis, (X, Y, Z));
+impl_common_vector_fns!(Vector3i, i32);
+impl_vector_bool_cmp!(Vector3i, Vector3b, (x, y, z));
+impl_vector_overflow_ops!(Vector3i, (x, y, z));
+impl_vector_int_math!(Vector3i, i32, i128, i64, (x, y, z));
+
+impl Vector3i {
+    /// Returns a `Vector3i` with the given components.
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Constructs a new `Vector3i` with all components set to `v`.
+    pub const fn splat(v: i32) -> Self {
+        Self::new(v, v, v)
+    }
+
+    /// Constructs a new `Vector3i` from a [`Vector3`]. The floating point coordinates will be
+    /// truncated.
+    pub const fn from_vector3(v: Vector3) -> Self {
+        Self {
+            x: v.x as i32,
+            y: v.y as i32,
+            z: v.z as i32,
+        }
+    }
+
+    /// Extends a [`Vector2i`] to a `Vector3i` by appending a `z` component.
+    pub const fn from_vector2i(v: Vector2i, z: i32) -> Self {
+        Self::new(v.x, v.y, z)
+    }
+
+    /// Drops the `z` component, returning the remaining ones as a [`Vector2i`].
+    pub const fn xy(self) -> Vector2i {
+        Vector2i::new(self.x, self.y)
+    }
+
+    /// Extends `self` to a [`Vector4i`] by appending a `w` component.
+    pub const fn extend(self, w: i32) -> Vector4i {
+        Vector4i::from_vector3i(self, w)
+    }
+
+    /// Alias for [`Self::extend`], named after the component it appends.
+    pub const fn with_w(self, w: i32) -> Vector4i {
+        self.extend(w)
+    }
+
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::splat(1);
+
+    /// Converts the corresponding `glam` type to `Self`.
+    fn from_glam(v: IVec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+
+    /// Converts `self` to the corresponding `glam` type.
+    fn to_glam(self) -> IVec3 {
+        IVec3::new(self.x, self.y, self.z)
+    }
+}
+
+/// Formats the vector like Godot: `(x, y, z)`.
+impl fmt::Display for Vector3i {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// SAFETY:
+// This type is represented as `Self` in Godot, so `*mut Self` is sound.
+unsafe impl GodotFfi for Vector3i {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+/// Enumerates the axes in a [`Vector3i`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[repr(i32)]
+pub enum Vector3iAxis {
+    /// The X axis.
+    X,
+
+    /// The Y axis.
+    Y,
+
+    /// The Z axis.
+    Z,
+}
+
+// SAFETY:
+// This type is represented as `Self` in Godot, so `*mut Self` is sound.
+unsafe impl GodotFfi for Vector3iAxis {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+impl GlamType for IVec3 {
+    type Mapped = Vector3i;
+
+    fn to_front(&self) -> Self::Mapped {
+        Vector3i::new(self.x, self.y, self.z)
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        IVec3::new(mapped.x, mapped.y, mapped.z)
+    }
+}
+
+impl GlamConv for Vector3i {
+    type Glam = IVec3;
+}
+
+/// Vector used for 3D math using 64-bit integer coordinates.
+///
+/// 3-element structure that can be used to represent 3D grid coordinates or sets of integers,
+/// for cases where [`Vector3i`]'s 32-bit components would overflow (large voxel grids, hashed
+/// coordinates, etc.).
+///
+/// Unlike [`Vector3i`], this is a Rust-only convenience type: Godot has no corresponding
+/// 64-bit vector, so `Vector3i64` does not implement [`GodotFfi`] and cannot be marshalled
+/// across the engine boundary.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector3i64 {
+    /// The vector's X component.
+    pub x: i64,
+
+    /// The vector's Y component.
+    pub y: i64,
+
+    /// The vector's Z component.
+    pub z: i64,
+}
+
+impl_vector_operators!(Vector3i64, i64, (x, y, z));
+impl_vector_index!(Vector3i64, i64, (x, y, z), Vector3iAxis, (X, Y, Z));
+impl_common_vector_fns!(Vector3i64, i64);
+impl_vector_overflow_ops!(Vector3i64, (x, y, z));
+impl_vector_int_math!(Vector3i64, i64, i128, i128, (x, y, z));
+
+impl Vector3i64 {
+    /// Returns a `Vector3i64` with the given components.
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Constructs a new `Vector3i64` with all components set to `v`.
+    pub const fn splat(v: i64) -> Self {
+        Self::new(v, v, v)
+    }
+
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::splat(1);
+
+    /// Narrows `self` to a [`Vector3i`], truncating any component that doesn't fit in `i32`.
+    pub fn as_vector3i(self) -> Vector3i {
+        Vector3i::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+
+    /// Narrows `self` to a [`Vector3i`], or returns `None` if any component overflows `i32`.
+    pub fn try_to_vector3i(self) -> Option<Vector3i> {
+        Some(Vector3i::new(
+            i32::try_from(self.x).ok()?,
+            i32::try_from(self.y).ok()?,
+            i32::try_from(self.z).ok()?,
+        ))
+    }
+
+    /// Converts the corresponding `glam` type to `Self`.
+    fn from_glam(v: I64Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+
+    /// Converts `self` to the corresponding `glam` type.
+    fn to_glam(self) -> I64Vec3 {
+        I64Vec3::new(self.x, self.y, self.z)
+    }
+}
+
+/// Widens a [`Vector3i`] into a [`Vector3i64`]. This conversion is always lossless.
+impl From<Vector3i> for Vector3i64 {
+    fn from(v: Vector3i) -> Self {
+        Self::new(v.x as i64, v.y as i64, v.z as i64)
+    }
+}
+
+/// Formats the vector like Godot: `(x, y, z)`.
+impl fmt::Display for Vector3i64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl GlamType for I64Vec3 {
+    type Mapped = Vector3i64;
+
+    fn to_front(&self) -> Self::Mapped {
+        Vector3i64::new(self.x, self.y, self.z)
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        I64Vec3::new(mapped.x, mapped.y, mapped.z)
+    }
+}
+
+impl GlamConv for Vector3i64 {
+    type Glam = I64Vec3;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coord_min_max() {
+        let a = Vector3i::new(1, 3, 5);
+        let b = Vector3i::new(0, 5, 2);
+        assert_eq!(a.coord_min(b), Vector3i::new(0, 3, 2));
+        assert_eq!(a.coord_max(b), Vector3i::new(1, 5, 5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let vector = Vector3i::default();
+        let expected_json = "{\"x\":0,\"y\":0,\"z\":0}";
+
+        crate::builtin::test_utils::roundtrip(&vector, expected_json);
+    }
+
+    #[test]
+    fn cmp_and_select() {
+        let a = Vector3i::new(1, 3, 5);
+        let b = Vector3i::new(0, 3, 2);
+
+        assert_eq!(a.cmpeq(b), Vector3b::new(false, true, false));
+        assert_eq!(a.cmpgt(b), Vector3b::new(true, false, true));
+
+        let mask = a.cmpgt(b);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(Vector3i::select(mask, a, b), Vector3i::new(1, 3, 5));
+    }
+
+    #[test]
+    fn overflow_aware_arithmetic() {
+        let a = Vector3i::new(i32::MAX, i32::MIN, 1);
+        let b = Vector3i::new(1, -1, 1);
+
+        assert_eq!(a.wrapping_add(b), Vector3i::new(i32::MIN, i32::MAX, 2));
+        assert_eq!(a.saturating_add(b), Vector3i::new(i32::MAX, i32::MIN, 2));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(Vector3i::ONE.checked_add(Vector3i::ONE), Some(Vector3i::splat(2)));
+    }
+
+    #[test]
+    fn dimension_conversion() {
+        let v = Vector3i::new(1, 2, 3);
+        assert_eq!(v.xy(), Vector2i::new(1, 2));
+        assert_eq!(Vector3i::from_vector2i(v.xy(), v.z), v);
+        assert_eq!(v.extend(4), Vector4i::new(1, 2, 3, 4));
+        assert_eq!(v.with_w(4), v.extend(4));
+    }
+
+    #[test]
+    fn integer_math_fns() {
+        let a = Vector3i::new(-3, 4, -5);
+
+        assert_eq!(a.abs(), Vector3i::new(3, 4, 5));
+        assert_eq!(a.signum(), Vector3i::new(-1, 1, -1));
+        assert_eq!(
+            a.clamp(Vector3i::splat(-1), Vector3i::splat(1)),
+            Vector3i::new(-1, 1, -1)
+        );
+        assert_eq!(a.element_sum(), -4);
+        assert_eq!(a.element_product(), 60);
+
+        let c = Vector3i::new(7, -7, 3);
+        assert_eq!(c.snapped(Vector3i::splat(5)), Vector3i::new(5, -5, 5));
+
+        // Exact ties round half away from zero.
+        let tie = Vector3i::new(5, -5, 5);
+        assert_eq!(tie.snapped(Vector3i::splat(2)), Vector3i::new(6, -6, 6));
+    }
+
+    #[test]
+    fn widen_and_narrow_64_bit() {
+        let small = Vector3i::new(1, -2, 3);
+        let wide = Vector3i64::from(small);
+        assert_eq!(wide, Vector3i64::new(1, -2, 3));
+        assert_eq!(wide.try_to_vector3i(), Some(small));
+
+        let overflowing = Vector3i64::new(i64::from(i32::MAX) + 1, 0, 0);
+        assert_eq!(overflowing.try_to_vector3i(), None);
+        assert_eq!(overflowing.as_vector3i(), Vector3i::new(i32::MIN, 0, 0));
+    }
+
+    #[test]
+    fn snapped_64_bit_preserves_precision() {
+        let huge = Vector3i64::splat(i64::MAX - 1);
+        assert_eq!(huge.snapped(Vector3i64::splat(1)), huge);
+
+        let tie = Vector3i64::new(5, -5, 5);
+        assert_eq!(tie.snapped(Vector3i64::splat(2)), Vector3i64::new(6, -6, 6));
+    }
+}
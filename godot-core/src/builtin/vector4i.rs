@@ -9,10 +9,10 @@ use std::fmt;
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use crate::builtin::Vector4;
+use crate::builtin::{Vector2i, Vector3i, Vector4};
 
 use super::glam_helpers::{GlamConv, GlamType};
-use super::IVec4;
+use super::{I64Vec4, IVec4};
 
 /// Vector used for 4D math using integer coordinates.
 ///
@@ -20,8 +20,8 @@ use super::IVec4;
 ///
 /// It uses integer coordinates and is therefore preferable to [`Vector4`] when exact precision is
 /// required. Note that the values are limited to 32 bits, and unlike [`Vector4`] this cannot be
-/// configured with an engine build option. Use `i64` or [`PackedInt64Array`] if 64-bit values are
-/// needed.
+/// configured with an engine build option. Use [`Vector4i64`] or [`PackedInt64Array`] if 64-bit
+/// values are needed.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -42,6 +42,9 @@ pub struct Vector4i {
 impl_vector_operators!(Vector4i, i32, (x, y, z, w));
 impl_vector_index!(Vector4i, i32, (x, y, z, w), Vector4iAxis, (X, Y, Z, W));
 impl_common_vector_fns!(Vector4i, i32);
+impl_vector_bool_cmp!(Vector4i, Vector4b, (x, y, z, w));
+impl_vector_overflow_ops!(Vector4i, (x, y, z, w));
+impl_vector_int_math!(Vector4i, i32, i128, i64, (x, y, z, w));
 
 impl Vector4i {
     /// Returns a `Vector4i` with the given components.
@@ -65,6 +68,49 @@ impl Vector4i {
         }
     }
 
+    /// Extends a [`Vector3i`] to a `Vector4i` by appending a `w` component.
+    pub const fn from_vector3i(v: Vector3i, w: i32) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+
+    /// Extends a [`Vector2i`] to a `Vector4i` by appending `z` and `w` components.
+    pub const fn from_vector2i(v: Vector2i, z: i32, w: i32) -> Self {
+        Self::new(v.x, v.y, z, w)
+    }
+
+    /// Drops the `z` and `w` components, returning the remaining ones as a [`Vector2i`].
+    pub const fn xy(self) -> Vector2i {
+        Vector2i::new(self.x, self.y)
+    }
+
+    /// Drops the `w` component, returning the remaining ones as a [`Vector3i`].
+    pub const fn xyz(self) -> Vector3i {
+        Vector3i::new(self.x, self.y, self.z)
+    }
+
+    /// Returns a new vector with components rearranged according to `x`, `y`, `z`, and `w`.
+    ///
+    /// For example, `v.swizzle(Vector4iAxis::W, Vector4iAxis::X, Vector4iAxis::X, Vector4iAxis::Y)`
+    /// is equivalent to `Vector4i::new(v.w, v.x, v.x, v.y)`.
+    pub const fn swizzle(
+        self,
+        x: Vector4iAxis,
+        y: Vector4iAxis,
+        z: Vector4iAxis,
+        w: Vector4iAxis,
+    ) -> Self {
+        Self::new(self.axis(x), self.axis(y), self.axis(z), self.axis(w))
+    }
+
+    const fn axis(self, axis: Vector4iAxis) -> i32 {
+        match axis {
+            Vector4iAxis::X => self.x,
+            Vector4iAxis::Y => self.y,
+            Vector4iAxis::Z => self.z,
+            Vector4iAxis::W => self.w,
+        }
+    }
+
     /// Zero vector, a vector with all components set to `0`.
     pub const ZERO: Self = Self::splat(0);
 
@@ -134,6 +180,111 @@ impl GlamConv for Vector4i {
     type Glam = IVec4;
 }
 
+/// Vector used for 4D math using 64-bit integer coordinates.
+///
+/// 4-element structure that can be used to represent 4D grid coordinates or sets of integers,
+/// for cases where [`Vector4i`]'s 32-bit components would overflow (large voxel grids, hashed
+/// coordinates, etc.).
+///
+/// Unlike [`Vector4i`], this is a Rust-only convenience type: Godot has no corresponding
+/// 64-bit vector, so `Vector4i64` does not implement [`GodotFfi`] and cannot be marshalled
+/// across the engine boundary.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector4i64 {
+    /// The vector's X component.
+    pub x: i64,
+
+    /// The vector's Y component.
+    pub y: i64,
+
+    /// The vector's Z component.
+    pub z: i64,
+
+    /// The vector's W component.
+    pub w: i64,
+}
+
+impl_vector_operators!(Vector4i64, i64, (x, y, z, w));
+impl_vector_index!(Vector4i64, i64, (x, y, z, w), Vector4iAxis, (X, Y, Z, W));
+impl_common_vector_fns!(Vector4i64, i64);
+impl_vector_overflow_ops!(Vector4i64, (x, y, z, w));
+impl_vector_int_math!(Vector4i64, i64, i128, i128, (x, y, z, w));
+
+impl Vector4i64 {
+    /// Returns a `Vector4i64` with the given components.
+    pub const fn new(x: i64, y: i64, z: i64, w: i64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Constructs a new `Vector4i64` with all components set to `v`.
+    pub const fn splat(v: i64) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    /// Zero vector, a vector with all components set to `0`.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// One vector, a vector with all components set to `1`.
+    pub const ONE: Self = Self::splat(1);
+
+    /// Narrows `self` to a [`Vector4i`], truncating any component that doesn't fit in `i32`.
+    pub fn as_vector4i(self) -> Vector4i {
+        Vector4i::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+
+    /// Narrows `self` to a [`Vector4i`], or returns `None` if any component overflows `i32`.
+    pub fn try_to_vector4i(self) -> Option<Vector4i> {
+        Some(Vector4i::new(
+            i32::try_from(self.x).ok()?,
+            i32::try_from(self.y).ok()?,
+            i32::try_from(self.z).ok()?,
+            i32::try_from(self.w).ok()?,
+        ))
+    }
+
+    /// Converts the corresponding `glam` type to `Self`.
+    fn from_glam(v: I64Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+
+    /// Converts `self` to the corresponding `glam` type.
+    fn to_glam(self) -> I64Vec4 {
+        I64Vec4::new(self.x, self.y, self.z, self.w)
+    }
+}
+
+/// Widens a [`Vector4i`] into a [`Vector4i64`]. This conversion is always lossless.
+impl From<Vector4i> for Vector4i64 {
+    fn from(v: Vector4i) -> Self {
+        Self::new(v.x as i64, v.y as i64, v.z as i64, v.w as i64)
+    }
+}
+
+/// Formats the vector like Godot: `(x, y, z, w)`.
+impl fmt::Display for Vector4i64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl GlamType for I64Vec4 {
+    type Mapped = Vector4i64;
+
+    fn to_front(&self) -> Self::Mapped {
+        Vector4i64::new(self.x, self.y, self.z, self.w)
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        I64Vec4::new(mapped.x, mapped.y, mapped.z, mapped.w)
+    }
+}
+
+impl GlamConv for Vector4i64 {
+    type Glam = I64Vec4;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -154,4 +305,112 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[test]
+    fn cmp_and_select() {
+        let a = Vector4i::new(1, 3, 5, 0);
+        let b = Vector4i::new(0, 3, 2, 1);
+
+        assert_eq!(a.cmpeq(b), Vector4b::new(false, true, false, false));
+        assert_eq!(a.cmpne(b), Vector4b::new(true, false, true, true));
+        assert_eq!(a.cmplt(b), Vector4b::new(false, false, false, true));
+        assert_eq!(a.cmple(b), Vector4b::new(false, true, false, true));
+        assert_eq!(a.cmpgt(b), Vector4b::new(true, false, true, false));
+        assert_eq!(a.cmpge(b), Vector4b::new(true, true, true, false));
+
+        let mask = a.cmpgt(b);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(Vector4i::select(mask, a, b), Vector4i::new(1, 3, 5, 1));
+    }
+
+    #[test]
+    fn widen_and_narrow_64_bit() {
+        let small = Vector4i::new(1, -2, 3, -4);
+        let wide = Vector4i64::from(small);
+        assert_eq!(wide, Vector4i64::new(1, -2, 3, -4));
+        assert_eq!(wide.try_to_vector4i(), Some(small));
+
+        let overflowing = Vector4i64::new(i64::from(i32::MAX) + 1, 0, 0, 0);
+        assert_eq!(overflowing.try_to_vector4i(), None);
+        assert_eq!(overflowing.as_vector4i(), Vector4i::new(i32::MIN, 0, 0, 0));
+    }
+
+    #[test]
+    fn overflow_aware_arithmetic() {
+        let a = Vector4i::new(i32::MAX, i32::MIN, 1, -1);
+        let b = Vector4i::new(1, -1, 1, 1);
+
+        assert_eq!(a.wrapping_add(b), Vector4i::new(i32::MIN, i32::MAX, 2, 0));
+        assert_eq!(a.saturating_add(b), Vector4i::new(i32::MAX, i32::MIN, 2, 0));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(Vector4i::ONE.checked_add(Vector4i::ONE), Some(Vector4i::splat(2)));
+    }
+
+    #[test]
+    fn integer_math_fns() {
+        let a = Vector4i::new(-3, 4, -5, 0);
+
+        assert_eq!(a.abs(), Vector4i::new(3, 4, 5, 0));
+        assert_eq!(a.signum(), Vector4i::new(-1, 1, -1, 0));
+        assert_eq!(
+            a.clamp(Vector4i::splat(-1), Vector4i::splat(1)),
+            Vector4i::new(-1, 1, -1, 0)
+        );
+        assert_eq!(a.element_sum(), -4);
+        assert_eq!(a.element_product(), 0);
+
+        let b = Vector4i::new(0, 0, 0, 0);
+        assert_eq!(a.length_squared(), 9 + 16 + 25);
+        assert_eq!(a.distance_squared(b), 9 + 16 + 25);
+
+        let c = Vector4i::new(7, -7, 10, 3);
+        assert_eq!(c.snapped(Vector4i::splat(5)), Vector4i::new(5, -5, 10, 5));
+
+        // Exact ties round half away from zero, matching the doc comment.
+        let tie = Vector4i::new(5, -5, 0, 0);
+        assert_eq!(tie.snapped(Vector4i::splat(2)), Vector4i::new(6, -6, 0, 0));
+    }
+
+    #[test]
+    fn snapped_64_bit_preserves_precision() {
+        // A coordinate far beyond f64's 53-bit exact-integer range; a float round-trip would
+        // silently corrupt this before rounding.
+        let huge = Vector4i64::splat(i64::MAX - 1);
+        assert_eq!(huge.snapped(Vector4i64::splat(1)), huge);
+
+        let tie = Vector4i64::new(5, -5, 0, 0);
+        assert_eq!(tie.snapped(Vector4i64::splat(2)), Vector4i64::new(6, -6, 0, 0));
+    }
+
+    #[test]
+    fn length_squared_64_bit_does_not_overflow_i64() {
+        // A single component near `i64::MAX` squares to far more than `i64::MAX` can hold;
+        // the accumulator must be wider than `i64` to represent this exactly.
+        let huge = Vector4i64::new(i64::MAX - 1, 0, 0, 0);
+        let expected = i128::from(i64::MAX - 1) * i128::from(i64::MAX - 1);
+
+        assert_eq!(huge.length_squared(), expected);
+        assert_eq!(huge.distance_squared(Vector4i64::ZERO), expected);
+    }
+
+    #[test]
+    fn dimension_conversion_and_swizzle() {
+        let v = Vector4i::new(1, 2, 3, 4);
+
+        assert_eq!(v.xy(), Vector2i::new(1, 2));
+        assert_eq!(v.xyz(), Vector3i::new(1, 2, 3));
+        assert_eq!(Vector4i::from_vector3i(v.xyz(), v.w), v);
+        assert_eq!(Vector4i::from_vector2i(v.xy(), v.z, v.w), v);
+
+        assert_eq!(
+            v.swizzle(
+                Vector4iAxis::W,
+                Vector4iAxis::X,
+                Vector4iAxis::X,
+                Vector4iAxis::Y
+            ),
+            Vector4i::new(4, 1, 1, 2)
+        );
+    }
 }
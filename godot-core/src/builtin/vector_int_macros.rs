@@ -0,0 +1,257 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Shared macros for the cross-cutting functionality that all integer vector types
+//! (`Vector2i`/`Vector3i`/`Vector4i` and their 64-bit counterparts) expose identically, so that
+//! adding a feature to one lane-count means adding it to all of them. The exception is
+//! `impl_vector_bool_cmp!`, which is only applied to the 32-bit types; the 64-bit vectors have
+//! no component-wise comparisons or `select`.
+
+/// Implements component-wise comparisons (`cmpeq`, `cmpne`, `cmplt`, `cmple`, `cmpgt`, `cmpge`)
+/// and `select` on `$Vector`, plus a companion boolean mask type `$Bool` (with `any`/`all`),
+/// mirroring glam's `BVec*` family.
+macro_rules! impl_vector_bool_cmp {
+    ($Vector:ident, $Bool:ident, ($($comp:ident),+)) => {
+        impl $Vector {
+            /// Returns a boolean vector with `true` in the lanes where `self` and `other` are equal.
+            pub fn cmpeq(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp == other.$comp),+ }
+            }
+
+            /// Returns a boolean vector with `true` in the lanes where `self` and `other` differ.
+            pub fn cmpne(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp != other.$comp),+ }
+            }
+
+            /// Returns a boolean vector with `true` in the lanes where `self` is less than `other`.
+            pub fn cmplt(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp < other.$comp),+ }
+            }
+
+            /// Returns a boolean vector with `true` in the lanes where `self` is less than or equal to `other`.
+            pub fn cmple(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp <= other.$comp),+ }
+            }
+
+            /// Returns a boolean vector with `true` in the lanes where `self` is greater than `other`.
+            pub fn cmpgt(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp > other.$comp),+ }
+            }
+
+            /// Returns a boolean vector with `true` in the lanes where `self` is greater than or equal to `other`.
+            pub fn cmpge(self, other: Self) -> $Bool {
+                $Bool { $($comp: self.$comp >= other.$comp),+ }
+            }
+
+            /// Picks each lane from `if_true` or `if_false` depending on the corresponding lane of `mask`.
+            pub fn select(mask: $Bool, if_true: Self, if_false: Self) -> Self {
+                Self { $($comp: if mask.$comp { if_true.$comp } else { if_false.$comp }),+ }
+            }
+        }
+
+        #[doc = concat!(
+            "Boolean vector produced by the component-wise comparison methods on [`", stringify!($Vector),
+            "`], such as [`", stringify!($Vector), "::cmpeq`]."
+        )]
+        #[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(C)]
+        pub struct $Bool {
+            $(
+                #[doc = concat!("The vector's ", stringify!($comp), " component.")]
+                pub $comp: bool,
+            )+
+        }
+
+        impl $Bool {
+            /// Returns a new vector with the given components.
+            pub const fn new($($comp: bool),+) -> Self {
+                Self { $($comp),+ }
+            }
+
+            /// Constructs a new vector with all components set to `v`.
+            pub const fn splat(v: bool) -> Self {
+                Self { $($comp: v),+ }
+            }
+
+            /// Vector with all components set to `false`.
+            pub const FALSE: Self = Self::splat(false);
+
+            /// Vector with all components set to `true`.
+            pub const TRUE: Self = Self::splat(true);
+
+            /// Returns `true` if at least one component is `true`.
+            pub const fn any(self) -> bool {
+                false $(|| self.$comp)+
+            }
+
+            /// Returns `true` if all components are `true`.
+            pub const fn all(self) -> bool {
+                true $(&& self.$comp)+
+            }
+        }
+
+        impl std::fmt::Display for $Bool {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "(")?;
+                let mut components = [$(self.$comp),+].into_iter();
+                if let Some(first) = components.next() {
+                    write!(f, "{first}")?;
+                }
+                for component in components {
+                    write!(f, ", {component}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+/// Implements element-wise `wrapping_*`, `saturating_*`, and `checked_*` add/sub/mul on
+/// `$Vector`, over the component tuple the caller already enumerates for the other vector macros.
+macro_rules! impl_vector_overflow_ops {
+    ($Vector:ident, ($($comp:ident),+)) => {
+        impl $Vector {
+            /// Component-wise wrapping addition.
+            pub const fn wrapping_add(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.wrapping_add(other.$comp)),+ }
+            }
+
+            /// Component-wise wrapping subtraction.
+            pub const fn wrapping_sub(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.wrapping_sub(other.$comp)),+ }
+            }
+
+            /// Component-wise wrapping multiplication.
+            pub const fn wrapping_mul(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.wrapping_mul(other.$comp)),+ }
+            }
+
+            /// Component-wise saturating addition.
+            pub const fn saturating_add(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.saturating_add(other.$comp)),+ }
+            }
+
+            /// Component-wise saturating subtraction.
+            pub const fn saturating_sub(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.saturating_sub(other.$comp)),+ }
+            }
+
+            /// Component-wise saturating multiplication.
+            pub const fn saturating_mul(self, other: Self) -> Self {
+                Self { $($comp: self.$comp.saturating_mul(other.$comp)),+ }
+            }
+
+            /// Component-wise checked addition. Returns `None` if any lane overflows.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self { $($comp: match self.$comp.checked_add(other.$comp) {
+                    Some(v) => v,
+                    None => return None,
+                }),+ })
+            }
+
+            /// Component-wise checked subtraction. Returns `None` if any lane overflows.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(Self { $($comp: match self.$comp.checked_sub(other.$comp) {
+                    Some(v) => v,
+                    None => return None,
+                }),+ })
+            }
+
+            /// Component-wise checked multiplication. Returns `None` if any lane overflows.
+            pub const fn checked_mul(self, other: Self) -> Option<Self> {
+                Some(Self { $($comp: match self.$comp.checked_mul(other.$comp) {
+                    Some(v) => v,
+                    None => return None,
+                }),+ })
+            }
+        }
+    };
+}
+
+/// Implements `abs`, `signum`, `clamp`, `length_squared`, `distance_squared`, `element_sum`,
+/// `element_product`, and `snapped` on `$Vector`. `$Wide` is the accumulator type used for the
+/// squared-length/distance methods; it should be wide enough that a single squared component
+/// doesn't overflow. For 64-bit vectors even `i128` cannot rule out overflow: `length_squared`
+/// overflows once several lanes are near `$Scalar::MAX`/`$Scalar::MIN`, and in
+/// `distance_squared` a single lane whose difference exceeds `2^63.5` already does.
+/// `$SnapWide` is the type `snapped` computes in internally; it is wider than `$Scalar`, so the
+/// rounding step itself cannot overflow. The result is cast back to `$Scalar`, which wraps when
+/// the nearest multiple lies outside `$Scalar`'s range.
+macro_rules! impl_vector_int_math {
+    ($Vector:ident, $Scalar:ty, $Wide:ty, $SnapWide:ty, ($($comp:ident),+)) => {
+        impl $Vector {
+            /// Returns a new vector with the absolute value of each component.
+            pub const fn abs(self) -> Self {
+                Self { $($comp: self.$comp.abs()),+ }
+            }
+
+            /// Returns a new vector with each component set to `-1`, `0`, or `1`, depending on
+            /// the sign of the corresponding component of `self`.
+            pub const fn signum(self) -> Self {
+                Self { $($comp: self.$comp.signum()),+ }
+            }
+
+            /// Clamps each component of `self` between the corresponding components of `min` and `max`.
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self { $($comp: self.$comp.clamp(min.$comp, max.$comp)),+ }
+            }
+
+            /// Squared length of the vector. Accumulates in `$Wide` to guard against overflow for
+            /// ordinary grid-scale coordinates; see the macro-level docs for the residual limit.
+            pub const fn length_squared(self) -> $Wide {
+                0 $(+ (self.$comp as $Wide) * (self.$comp as $Wide))+
+            }
+
+            /// Squared distance to `other`. Accumulates in `$Wide` to guard against overflow for
+            /// ordinary grid-scale coordinates; see the macro-level docs for the residual limit.
+            pub const fn distance_squared(self, other: Self) -> $Wide {
+                0 $(+ {
+                    let d = self.$comp as $Wide - other.$comp as $Wide;
+                    d * d
+                })+
+            }
+
+            /// Sum of all components.
+            pub const fn element_sum(self) -> $Scalar {
+                0 $(+ self.$comp)+
+            }
+
+            /// Product of all components.
+            pub const fn element_product(self) -> $Scalar {
+                1 $(* self.$comp)+
+            }
+
+            /// Snaps each component of `self` to the nearest multiple of the corresponding
+            /// component of `step`, rounding half away from zero on ties. A `step` component of
+            /// `0` leaves the corresponding component of `self` unchanged.
+            pub fn snapped(self, step: Self) -> Self {
+                Self { $($comp: Self::snap_axis(self.$comp, step.$comp)),+ }
+            }
+
+            /// Rounds `value` to the nearest multiple of `step`, half away from zero. Computed
+            /// purely with integer arithmetic (widened to `$SnapWide`) so it stays exact, unlike
+            /// a float round-trip.
+            fn snap_axis(value: $Scalar, step: $Scalar) -> $Scalar {
+                if step == 0 {
+                    return value;
+                }
+
+                let value = value as $SnapWide;
+                let step = step as $SnapWide;
+                let step = if step < 0 { -step } else { step };
+
+                let snapped = if value >= 0 {
+                    (value + step / 2) / step
+                } else {
+                    -((-value + step / 2) / step)
+                };
+
+                (snapped * step) as $Scalar
+            }
+        }
+    };
+}